@@ -2,15 +2,21 @@ use biome_analyze::{
     context::RuleContext, declare_lint_rule, FixKind, Rule, RuleDiagnostic, RuleSource,
 };
 use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
 use biome_js_factory::make;
 use biome_js_semantic::SemanticModel;
 use biome_js_syntax::{
-    binding_ext::AnyJsBindingDeclaration, AnyJsExpression, AnyJsName, AnyTsName, AnyTsReturnType,
-    AnyTsType, AnyTsVariableAnnotation, JsArrowFunctionExpression, JsCallExpression,
-    JsExpressionStatement, JsFunctionDeclaration, JsMethodClassMember, JsMethodObjectMember,
-    JsStaticMemberExpression, JsSyntaxKind, JsVariableDeclarator, TsReturnTypeAnnotation,
+    binding_ext::AnyJsBindingDeclaration, AnyJsArrowFunctionParameters, AnyJsBinding,
+    AnyJsBindingPattern, AnyJsExpression, AnyJsFormalParameter, AnyJsFunctionBody, AnyJsName,
+    AnyJsParameter, AnyJsStatement, AnyTsName, AnyTsReturnType, AnyTsType, AnyTsTypeMember,
+    AnyTsVariableAnnotation, JsArrowFunctionExpression, JsCallExpression, JsExpressionStatement,
+    JsFunctionDeclaration, JsMethodClassMember, JsMethodObjectMember, JsNewExpression,
+    JsParameters, JsStaticMemberExpression, JsSyntaxKind, JsSyntaxNode, JsVariableDeclarator,
+    TsReturnTypeAnnotation,
 };
 use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt, SyntaxNodeCast, TriviaPieceKind};
+use rustc_hash::FxHashSet;
+use serde::{Deserialize, Serialize};
 
 use crate::{services::semantic::Semantic, JsRuleAction};
 
@@ -85,75 +91,271 @@ declare_lint_rule! {
     }
 }
 
+/// A promise-valued expression left floating in a statement, together with the
+/// reason it was flagged.
+#[derive(Clone)]
+pub struct FloatingPromise {
+    /// The offending expression, used for both the diagnostic range and the fix.
+    expression: AnyJsExpression,
+    /// Why the expression was reported.
+    kind: FloatingPromiseKind,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum FloatingPromiseKind {
+    /// The Promise is not awaited, returned, voided nor given a handler.
+    Unhandled,
+    /// A `.then`/`.catch` rejection handler is present but can never handle a
+    /// rejection (e.g. `undefined`, `null` or another non-callable value).
+    UselessRejectionHandler,
+}
+
 impl Rule for NoFloatingPromises {
     type Query = Semantic<JsExpressionStatement>;
-    type State = ();
-    type Signals = Option<Self::State>;
-    type Options = ();
+    type State = FloatingPromise;
+    type Signals = Box<[Self::State]>;
+    type Options = NoFloatingPromisesOptions;
 
     fn run(ctx: &RuleContext<Self>) -> Self::Signals {
         let node = ctx.query();
         let model = ctx.model();
-        let expression = node.expression().ok()?;
-        if let AnyJsExpression::JsCallExpression(js_call_expression) = expression {
-            let Ok(any_js_expression) = js_call_expression.callee() else {
-                return None;
-            };
-
-            if !is_callee_a_promise(&any_js_expression, model) {
-                return None;
-            }
+        let options = ctx.options();
+        let Ok(expression) = node.expression() else {
+            return Box::new([]);
+        };
 
-            if is_handled_promise(&js_call_expression) {
-                return None;
-            }
-
-            return Some(());
-        }
-        None
+        let mut floating = Vec::new();
+        collect_floating_promises(&expression, model, options, &mut floating);
+        floating.into_boxed_slice()
     }
 
-    fn diagnostic(ctx: &RuleContext<Self>, _state: &Self::State) -> Option<RuleDiagnostic> {
-        let node = ctx.query();
-        Some(
-            RuleDiagnostic::new(
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state.kind {
+            FloatingPromiseKind::Unhandled => RuleDiagnostic::new(
                 rule_category!(),
-                node.range(),
+                state.expression.range(),
                 markup! {
                     "A \"floating\" Promise was found, meaning it is not properly handled and could lead to ignored errors or unexpected behavior."
                 },
             )
             .note(markup! {
                 "This happens when a Promise is not awaited, lacks a `.catch` or `.then` rejection handler, or is not explicitly ignored using the `void` operator."
-            })
-        )
+            }),
+            FloatingPromiseKind::UselessRejectionHandler => RuleDiagnostic::new(
+                rule_category!(),
+                state.expression.range(),
+                markup! {
+                    "This rejection handler is a no-op, so the Promise is still floating."
+                },
+            )
+            .note(markup! {
+                "A rejection handler only handles errors when it is a callable value. Passing `undefined`, `null` or another non-function leaves the Promise unhandled."
+            }),
+        };
+        Some(diagnostic)
     }
 
-    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
         let node = ctx.query();
+        let expression = state.expression.clone();
+
+        if is_in_async_function(node) {
+            // Prefer rewriting a `.then(...).catch(...)` chain into
+            // `await`/`try`/`catch` statements when the shape allows it. This
+            // replaces the whole statement, so it is only sound when the
+            // offending expression *is* the statement's top-level expression;
+            // a promise buried in a ternary/logical/sequence context falls
+            // through to the plain `await` fix, which rewrites just that
+            // sub-expression.
+            if node.expression().ok().as_ref() == Some(&expression) {
+                if let Some(action) = convert_then_chain_to_async(ctx, node, &expression) {
+                    return Some(action);
+                }
+            }
+
+            let mut mutation = ctx.root().begin();
+            let await_expression = AnyJsExpression::JsAwaitExpression(make::js_await_expression(
+                make::token(JsSyntaxKind::AWAIT_KW)
+                    .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                expression.clone().trim_leading_trivia()?,
+            ));
+
+            mutation.replace_node(expression, await_expression);
+            return Some(JsRuleAction::new(
+                ctx.metadata().action_category(ctx.category(), ctx.group()),
+                ctx.metadata().applicability(),
+                markup! { "Add await operator." }.to_owned(),
+                mutation,
+            ));
+        }
 
-        if !is_in_async_function(node) {
+        // Outside of an async context `await` is not available, so offer to
+        // discard the Promise explicitly with the `void` operator instead.
+        if !ctx.options().ignore_void {
             return None;
         }
 
-        let expression = node.expression().ok()?;
         let mut mutation = ctx.root().begin();
-        let await_expression = AnyJsExpression::JsAwaitExpression(make::js_await_expression(
-            make::token(JsSyntaxKind::AWAIT_KW)
+        let void_expression = AnyJsExpression::JsUnaryExpression(make::js_unary_expression(
+            make::token(JsSyntaxKind::VOID_KW)
                 .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
             expression.clone().trim_leading_trivia()?,
         ));
 
-        mutation.replace_node(expression, await_expression);
+        mutation.replace_node(expression, void_expression);
         Some(JsRuleAction::new(
             ctx.metadata().action_category(ctx.category(), ctx.group()),
             ctx.metadata().applicability(),
-            markup! { "Add await operator." }.to_owned(),
+            markup! { "Add void operator to ignore the Promise." }.to_owned(),
             mutation,
         ))
     }
 }
 
+/// Options for the [`NoFloatingPromises`] rule.
+#[derive(Clone, Debug, Deserializable, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoFloatingPromisesOptions {
+    /// Whether to ignore Promises explicitly discarded with the `void` operator.
+    ///
+    /// Defaults to `true`.
+    pub ignore_void: bool,
+
+    /// Whether to ignore immediately invoked function expressions (IIFEs).
+    ///
+    /// Defaults to `false`.
+    pub ignore_iife: bool,
+}
+
+impl Default for NoFloatingPromisesOptions {
+    fn default() -> Self {
+        Self {
+            ignore_void: true,
+            ignore_iife: false,
+        }
+    }
+}
+
+/// Checks whether `callee` is an immediately invoked async function expression.
+///
+/// This matches an async arrow or function expression that is the callee of the
+/// call being inspected, optionally wrapped in parentheses, as in
+/// `(async () => { await doWork() })()`.
+fn is_immediately_invoked_function(callee: &AnyJsExpression) -> bool {
+    let expr = callee.clone().omit_parentheses();
+    match expr {
+        AnyJsExpression::JsArrowFunctionExpression(arrow_func) => arrow_func.async_token().is_some(),
+        AnyJsExpression::JsFunctionExpression(func_expr) => func_expr.async_token().is_some(),
+        _ => false,
+    }
+}
+
+/// Collects every promise-valued sub-expression of `expression` that appears in
+/// a statement position and is left floating.
+///
+/// Besides a bare call statement, promises are routinely discarded inside the
+/// branches of a ternary, on the right-hand side of a `&&`/`||`/`??`
+/// expression, and in the operands of a comma sequence. Each of those contexts
+/// is inspected recursively so the reported set matches how promises are
+/// actually dropped.
+fn collect_floating_promises(
+    expression: &AnyJsExpression,
+    model: &SemanticModel,
+    options: &NoFloatingPromisesOptions,
+    floating: &mut Vec<FloatingPromise>,
+) {
+    match expression {
+        AnyJsExpression::JsParenthesizedExpression(parenthesized) => {
+            if let Ok(inner) = parenthesized.expression() {
+                collect_floating_promises(&inner, model, options, floating);
+            }
+        }
+        AnyJsExpression::JsConditionalExpression(conditional) => {
+            if let Ok(consequent) = conditional.consequent() {
+                collect_floating_promises(&consequent, model, options, floating);
+            }
+            if let Ok(alternate) = conditional.alternate() {
+                collect_floating_promises(&alternate, model, options, floating);
+            }
+        }
+        AnyJsExpression::JsLogicalExpression(logical) => {
+            if let Ok(right) = logical.right() {
+                collect_floating_promises(&right, model, options, floating);
+            }
+        }
+        AnyJsExpression::JsSequenceExpression(sequence) => {
+            if let Ok(left) = sequence.left() {
+                collect_floating_promises(&left, model, options, floating);
+            }
+            if let Ok(right) = sequence.right() {
+                collect_floating_promises(&right, model, options, floating);
+            }
+        }
+        // With `ignoreVoid` disabled, `void` does not discard the Promise, so
+        // its operand is inspected like any other statement expression.
+        AnyJsExpression::JsUnaryExpression(unary)
+            if !options.ignore_void
+                && unary
+                    .operator_token()
+                    .is_ok_and(|token| token.kind() == JsSyntaxKind::VOID_KW) =>
+        {
+            if let Ok(argument) = unary.argument() {
+                collect_floating_promises(&argument, model, options, floating);
+            }
+        }
+        _ => {
+            if let Some(kind) = floating_promise_kind(expression, model, options) {
+                floating.push(FloatingPromise {
+                    expression: expression.clone(),
+                    kind,
+                });
+            }
+        }
+    }
+}
+
+/// Classifies `expression` as a floating Promise, if it is one.
+///
+/// This is the reusable core of the rule: it returns a [`FloatingPromiseKind`]
+/// when `expression` is a call whose callee resolves to a Promise and that is
+/// neither awaited, returned nor voided — either because it carries no handler
+/// at all or because the handler it carries is a no-op.
+fn floating_promise_kind(
+    expression: &AnyJsExpression,
+    model: &SemanticModel,
+    options: &NoFloatingPromisesOptions,
+) -> Option<FloatingPromiseKind> {
+    // `new Promise(...)` used as a bare statement is always floating: it carries
+    // no handler of its own.
+    if let AnyJsExpression::JsNewExpression(new_expression) = expression {
+        return match new_expression.callee() {
+            Ok(callee) if is_global_promise_reference(&callee, model) => {
+                Some(FloatingPromiseKind::Unhandled)
+            }
+            _ => None,
+        };
+    }
+
+    let AnyJsExpression::JsCallExpression(js_call_expression) = expression else {
+        return None;
+    };
+    let Ok(callee) = js_call_expression.callee() else {
+        return None;
+    };
+    if options.ignore_iife && is_immediately_invoked_function(&callee) {
+        return None;
+    }
+    if !is_callee_a_promise(&callee, model) {
+        return None;
+    }
+    match promise_rejection_handling(js_call_expression, model) {
+        PromiseHandling::Handled => None,
+        PromiseHandling::Useless => Some(FloatingPromiseKind::UselessRejectionHandler),
+        PromiseHandling::Unhandled => Some(FloatingPromiseKind::Unhandled),
+    }
+}
+
 /// Checks if the callee of a JavaScript expression is a promise.
 ///
 /// This function inspects the callee of a given JavaScript expression to determine
@@ -206,25 +408,65 @@ fn is_callee_a_promise(callee: &AnyJsExpression, model: &SemanticModel) -> bool
             };
             match any_js_binding_decl {
                 AnyJsBindingDeclaration::JsFunctionDeclaration(func_decl) => {
-                    is_function_a_promise(&func_decl)
+                    is_function_a_promise(&func_decl, model)
                 }
                 AnyJsBindingDeclaration::JsVariableDeclarator(js_var_decl) => {
-                    is_variable_initializer_a_promise(&js_var_decl)
-                        || is_variable_annotation_a_promise(&js_var_decl)
+                    is_variable_initializer_a_promise(&js_var_decl, model)
+                        || is_variable_annotation_a_promise(&js_var_decl, model)
                 }
                 _ => false,
             }
         }
         AnyJsExpression::JsStaticMemberExpression(static_member_expr) => {
-            is_member_expression_callee_a_promise(static_member_expr, model)
+            is_promise_static_combinator(static_member_expr, model)
+                || is_member_expression_callee_a_promise(static_member_expr, model)
         }
-        _ => false,
+        // An immediately invoked async function expression evaluates to a
+        // Promise, e.g. `(async () => { await doWork() })()`. Recognizing it
+        // here is what gives the `ignoreIIFE` option something to suppress.
+        _ => is_immediately_invoked_function(callee),
     }
 }
 
-fn is_function_a_promise(func_decl: &JsFunctionDeclaration) -> bool {
+/// Checks whether `expression` is the global `Promise` identifier.
+///
+/// The binding is resolved through the semantic model so a locally redefined
+/// `Promise` (e.g. a parameter or import) is *not* treated as the global, which
+/// avoids false positives on shadowed symbols.
+fn is_global_promise_reference(expression: &AnyJsExpression, model: &SemanticModel) -> bool {
+    let AnyJsExpression::JsIdentifierExpression(ident_expr) = expression else {
+        return false;
+    };
+    let Ok(reference) = ident_expr.name() else {
+        return false;
+    };
+    reference.has_name("Promise") && model.binding(&reference).is_none()
+}
+
+/// Checks whether `static_member_expr` is a call to a `Promise` static
+/// combinator or resolver, such as `Promise.all`, `Promise.race`,
+/// `Promise.allSettled`, `Promise.resolve` or `Promise.reject`.
+fn is_promise_static_combinator(
+    static_member_expr: &JsStaticMemberExpression,
+    model: &SemanticModel,
+) -> bool {
+    let Ok(AnyJsName::JsName(member)) = static_member_expr.member() else {
+        return false;
+    };
+    if !matches!(
+        member.to_string().as_str(),
+        "all" | "allSettled" | "race" | "any" | "resolve" | "reject"
+    ) {
+        return false;
+    }
+    static_member_expr
+        .object()
+        .is_ok_and(|object| is_global_promise_reference(&object, model))
+}
+
+fn is_function_a_promise(func_decl: &JsFunctionDeclaration, model: &SemanticModel) -> bool {
     func_decl.async_token().is_some()
-        || is_return_type_a_promise(func_decl.return_type_annotation())
+        || is_return_type_a_promise(func_decl.return_type_annotation(), model)
 }
 
 /// Checks if a TypeScript return type annotation is a `Promise`.
@@ -252,30 +494,125 @@ fn is_function_a_promise(func_decl: &JsFunctionDeclaration) -> bool {
 /// ```typescript
 /// function doesNotReturnPromise(): void {}
 /// ```
-fn is_return_type_a_promise(return_type: Option<TsReturnTypeAnnotation>) -> bool {
+///
+/// Detection is type-aware rather than a syntactic name match: a return type
+/// resolves to a Promise when it refers (possibly through alias chains) to
+/// `Promise`/`PromiseLike`, when it is a union with at least one promise-like
+/// member, or when it is a structural thenable.
+fn is_return_type_a_promise(
+    return_type: Option<TsReturnTypeAnnotation>,
+    model: &SemanticModel,
+) -> bool {
     return_type
         .and_then(|ts_return_type_anno| ts_return_type_anno.ty().ok())
         .and_then(|any_ts_return_type| match any_ts_return_type {
             AnyTsReturnType::AnyTsType(any_ts_type) => Some(any_ts_type),
             _ => None,
         })
-        .and_then(|any_ts_type| match any_ts_type {
-            AnyTsType::TsReferenceType(reference_type) => Some(reference_type),
-            _ => None,
-        })
-        .and_then(|reference_type| reference_type.name().ok())
-        .and_then(|name| match name {
-            AnyTsName::JsReferenceIdentifier(identifier) => Some(identifier),
-            _ => None,
+        .is_some_and(|any_ts_type| {
+            is_type_promise_like(&any_ts_type, model, &mut FxHashSet::default())
         })
-        .map_or(false, |reference| reference.has_name("Promise"))
 }
 
-/// Checks if a `JsCallExpression` is a handled Promise-like expression.
-/// - Calling its .then() with two arguments
-/// - Calling its .catch() with one argument
+/// Classifies a TypeScript type as "promise-like".
 ///
-/// Example TypeScript code that would return `true`:
+/// A type is promise-like when it refers — possibly through a chain of type
+/// alias declarations — to `Promise` or `PromiseLike`, when it is a union or
+/// intersection with at least one promise-like member, or when it is a
+/// structural thenable (an object type exposing a callable `then` member).
+///
+/// Alias and union members are walked recursively; `visited` records the alias
+/// names already seen so cyclic aliases such as `type A = B; type B = A` do not
+/// recurse forever. The walk short-circuits as soon as one promise-like arm is
+/// found.
+fn is_type_promise_like(
+    ty: &AnyTsType,
+    model: &SemanticModel,
+    visited: &mut FxHashSet<String>,
+) -> bool {
+    match ty {
+        AnyTsType::TsReferenceType(reference_type) => {
+            let Ok(AnyTsName::JsReferenceIdentifier(identifier)) = reference_type.name() else {
+                return false;
+            };
+            if identifier.has_name("Promise") || identifier.has_name("PromiseLike") {
+                return true;
+            }
+            let Ok(name_token) = identifier.value_token() else {
+                return false;
+            };
+            let name = name_token.text_trimmed().to_string();
+            if !visited.insert(name) {
+                return false;
+            }
+            // Resolve the reference through its declaration and, if it is a type
+            // alias, keep classifying the aliased type.
+            let Some(binding) = model.binding(&identifier) else {
+                return false;
+            };
+            let Some(AnyJsBindingDeclaration::TsTypeAliasDeclaration(alias)) =
+                binding.tree().declaration()
+            else {
+                return false;
+            };
+            alias
+                .ty()
+                .is_ok_and(|aliased| is_type_promise_like(&aliased, model, visited))
+        }
+        AnyTsType::TsUnionType(union_type) => union_type
+            .types()
+            .iter()
+            .filter_map(|member| member.ok())
+            .any(|member| is_type_promise_like(&member, model, visited)),
+        AnyTsType::TsIntersectionType(intersection_type) => intersection_type
+            .types()
+            .iter()
+            .filter_map(|member| member.ok())
+            .any(|member| is_type_promise_like(&member, model, visited)),
+        AnyTsType::TsParenthesizedType(parenthesized_type) => parenthesized_type
+            .ty()
+            .is_ok_and(|inner| is_type_promise_like(&inner, model, visited)),
+        AnyTsType::TsObjectType(object_type) => {
+            object_type.members().into_iter().any(|member| match member {
+                AnyTsTypeMember::TsMethodSignatureTypeMember(method) => method
+                    .name()
+                    .is_ok_and(|name| name.name().is_some_and(|name| name == "then")),
+                AnyTsTypeMember::TsPropertySignatureTypeMember(property) => {
+                    let is_then = property
+                        .name()
+                        .is_ok_and(|name| name.name().is_some_and(|name| name == "then"));
+                    is_then
+                        && matches!(
+                            property.ty().and_then(|anno| anno.ty().ok()),
+                            Some(AnyTsType::TsFunctionType(_))
+                        )
+                }
+                _ => false,
+            })
+        }
+        _ => false,
+    }
+}
+
+/// How the rejection of a Promise call expression is (or is not) dealt with.
+enum PromiseHandling {
+    /// The rejection is handled by a plausibly callable handler.
+    Handled,
+    /// A `.then`/`.catch` handler slot is present but cannot handle a rejection.
+    Useless,
+    /// No rejection handler is attached.
+    Unhandled,
+}
+
+/// Determines how a `JsCallExpression` deals with the rejection of its Promise.
+///
+/// A rejection is only considered handled when the handler argument is
+/// plausibly callable, so `promise.then(onOk, undefined)`, `promise.catch(null)`
+/// and similar no-ops are reported as [`PromiseHandling::Useless`] rather than
+/// slipping through on arity alone. `.finally()` never handles rejection, so it
+/// is transparent and the inner call is inspected instead.
+///
+/// Example TypeScript code whose rejection is handled:
 ///
 /// ```typescript
 /// const promise: Promise<unknown> = new Promise((resolve, reject) => resolve('value'));
@@ -284,45 +621,116 @@ fn is_return_type_a_promise(return_type: Option<TsReturnTypeAnnotation>) -> bool
 /// const promise: Promise<unknown> = new Promise((resolve, reject) => resolve('value'));
 /// promise.then(() => "aaa").catch(() => null).finally(() => null)
 /// ```
-fn is_handled_promise(js_call_expression: &JsCallExpression) -> bool {
-    let Ok(expr) = js_call_expression.callee() else {
-        return false;
-    };
-
-    let AnyJsExpression::JsStaticMemberExpression(static_member_expr) = expr else {
-        return false;
+fn promise_rejection_handling(
+    js_call_expression: &JsCallExpression,
+    model: &SemanticModel,
+) -> PromiseHandling {
+    let Ok(AnyJsExpression::JsStaticMemberExpression(static_member_expr)) =
+        js_call_expression.callee()
+    else {
+        return PromiseHandling::Unhandled;
     };
 
     let Ok(AnyJsName::JsName(name)) = static_member_expr.member() else {
-        return false;
+        return PromiseHandling::Unhandled;
     };
 
-    let name = name.to_string();
-
-    if name == "finally" {
-        if let Ok(expr) = static_member_expr.object() {
-            if let Some(callee) = expr.as_js_call_expression() {
-                return is_handled_promise(callee);
+    match name.to_string().as_str() {
+        "finally" => {
+            if let Ok(expr) = static_member_expr.object() {
+                if let Some(callee) = expr.as_js_call_expression() {
+                    return promise_rejection_handling(callee, model);
+                }
             }
+            PromiseHandling::Unhandled
         }
-    }
-    if name == "catch" {
-        if let Ok(call_args) = js_call_expression.arguments() {
-            // just checking if there are any arguments, not if it's a function for simplicity
-            if call_args.args().len() > 0 {
-                return true;
+        // The rejection handler is the single argument of `.catch`.
+        "catch" => match rejection_handler_argument(js_call_expression, 0) {
+            None => PromiseHandling::Unhandled,
+            Some(argument) if is_plausible_rejection_handler(&argument, model) => {
+                PromiseHandling::Handled
             }
-        }
+            Some(_) => PromiseHandling::Useless,
+        },
+        // The rejection handler is the second argument of `.then`.
+        "then" => match rejection_handler_argument(js_call_expression, 1) {
+            None => PromiseHandling::Unhandled,
+            Some(argument) if is_plausible_rejection_handler(&argument, model) => {
+                PromiseHandling::Handled
+            }
+            Some(_) => PromiseHandling::Useless,
+        },
+        _ => PromiseHandling::Unhandled,
     }
-    if name == "then" {
-        if let Ok(call_args) = js_call_expression.arguments() {
-            // just checking arguments have a reject function from length
-            if call_args.args().len() >= 2 {
+}
+
+/// Returns the expression passed as the `index`-th argument of a call, if any.
+fn rejection_handler_argument(
+    js_call_expression: &JsCallExpression,
+    index: usize,
+) -> Option<AnyJsExpression> {
+    js_call_expression
+        .arguments()
+        .ok()?
+        .args()
+        .iter()
+        .filter_map(|arg| arg.ok())
+        .nth(index)
+        .and_then(|arg| arg.as_any_js_expression().cloned())
+}
+
+/// Checks whether `argument` could actually handle a rejection at runtime.
+///
+/// `undefined`, `null` and other literals are never callable, and an identifier
+/// resolved through the semantic model to a non-function value is rejected too.
+/// Anything the model cannot disprove is assumed callable to avoid false
+/// positives.
+fn is_plausible_rejection_handler(argument: &AnyJsExpression, model: &SemanticModel) -> bool {
+    match argument.clone().omit_parentheses() {
+        AnyJsExpression::JsArrowFunctionExpression(_)
+        | AnyJsExpression::JsFunctionExpression(_) => true,
+        AnyJsExpression::AnyJsLiteralExpression(_) => false,
+        AnyJsExpression::JsIdentifierExpression(ident_expr) => {
+            let Ok(reference) = ident_expr.name() else {
+                return false;
+            };
+            if reference.has_name("undefined") {
+                return false;
+            }
+            let Some(binding) = model.binding(&reference) else {
+                // Unresolved reference (e.g. a global): assume it is callable.
                 return true;
+            };
+            match binding.tree().declaration() {
+                Some(AnyJsBindingDeclaration::JsVariableDeclarator(declarator)) => {
+                    is_initializer_callable(&declarator)
+                }
+                // Functions, classes, parameters, imports, ... may be callable.
+                _ => true,
             }
         }
+        _ => true,
+    }
+}
+
+/// Checks whether the initializer of a variable is something callable.
+fn is_initializer_callable(js_variable_declarator: &JsVariableDeclarator) -> bool {
+    let Some(initializer) = js_variable_declarator.initializer() else {
+        return true;
+    };
+    let Ok(expr) = initializer.expression() else {
+        return true;
+    };
+    match expr.omit_parentheses() {
+        AnyJsExpression::JsArrowFunctionExpression(_)
+        | AnyJsExpression::JsFunctionExpression(_) => true,
+        AnyJsExpression::AnyJsLiteralExpression(_) => false,
+        AnyJsExpression::JsIdentifierExpression(ident_expr) => !ident_expr
+            .name()
+            .map(|reference| reference.has_name("undefined"))
+            .unwrap_or(false),
+        _ => true,
     }
-    false
 }
 
 /// Checks if the callee of a `JsStaticMemberExpression` is a promise expression.
@@ -364,15 +772,15 @@ fn is_member_expression_callee_a_promise(
         return false;
     };
 
-    let AnyJsExpression::JsCallExpression(js_call_expr) = expr else {
-        return false;
-    };
-
-    let Ok(callee) = js_call_expr.callee() else {
-        return false;
-    };
-
-    is_callee_a_promise(&callee, model)
+    match expr {
+        AnyJsExpression::JsCallExpression(js_call_expr) => js_call_expr
+            .callee()
+            .is_ok_and(|callee| is_callee_a_promise(&callee, model)),
+        AnyJsExpression::JsNewExpression(new_expr) => new_expr
+            .callee()
+            .is_ok_and(|callee| is_global_promise_reference(&callee, model)),
+        _ => false,
+    }
 }
 
 /// Checks if the given `JsExpressionStatement` is within an async function.
@@ -424,7 +832,10 @@ fn is_in_async_function(node: &JsExpressionStatement) -> bool {
 ///   return 'value'
 /// }
 /// ```
-fn is_variable_initializer_a_promise(js_variable_declarator: &JsVariableDeclarator) -> bool {
+fn is_variable_initializer_a_promise(
+    js_variable_declarator: &JsVariableDeclarator,
+    model: &SemanticModel,
+) -> bool {
     let Some(initializer_clause) = &js_variable_declarator.initializer() else {
         return false;
     };
@@ -434,11 +845,11 @@ fn is_variable_initializer_a_promise(js_variable_declarator: &JsVariableDeclarat
     match expr {
         AnyJsExpression::JsArrowFunctionExpression(arrow_func) => {
             arrow_func.async_token().is_some()
-                || is_return_type_a_promise(arrow_func.return_type_annotation())
+                || is_return_type_a_promise(arrow_func.return_type_annotation(), model)
         }
         AnyJsExpression::JsFunctionExpression(func_expr) => {
             func_expr.async_token().is_some()
-                || is_return_type_a_promise(func_expr.return_type_annotation())
+                || is_return_type_a_promise(func_expr.return_type_annotation(), model)
         }
         _ => false,
     }
@@ -453,32 +864,391 @@ fn is_variable_initializer_a_promise(js_variable_declarator: &JsVariableDeclarat
 ///   return Promise.resolve("value")
 /// }
 /// ```
-fn is_variable_annotation_a_promise(js_variable_declarator: &JsVariableDeclarator) -> bool {
-    js_variable_declarator
-        .variable_annotation()
-        .and_then(|anno| match anno {
-            AnyTsVariableAnnotation::TsTypeAnnotation(type_anno) => Some(type_anno),
-            _ => None,
-        })
-        .and_then(|ts_type_anno| ts_type_anno.ty().ok())
-        .and_then(|any_ts_type| match any_ts_type {
-            AnyTsType::TsFunctionType(func_type) => {
-                func_type
-                    .return_type()
-                    .ok()
-                    .and_then(|return_type| match return_type {
-                        AnyTsReturnType::AnyTsType(AnyTsType::TsReferenceType(ref_type)) => {
-                            ref_type.name().ok().map(|name| match name {
-                                AnyTsName::JsReferenceIdentifier(identifier) => {
-                                    identifier.has_name("Promise")
-                                }
-                                _ => false,
-                            })
-                        }
-                        _ => None,
-                    })
+fn is_variable_annotation_a_promise(
+    js_variable_declarator: &JsVariableDeclarator,
+    model: &SemanticModel,
+) -> bool {
+    let Some(AnyTsVariableAnnotation::TsTypeAnnotation(type_anno)) =
+        js_variable_declarator.variable_annotation()
+    else {
+        return false;
+    };
+    let Ok(any_ts_type) = type_anno.ty() else {
+        return false;
+    };
+    // For `const f: () => Promise<T>` — or its aliased form
+    // `type Fetch = () => Promise<R>; const f: Fetch` — the variable itself is a
+    // function, so classify the return type of the annotated signature.
+    // Otherwise the annotation may itself be a Promise-valued type.
+    is_callable_returning_promise(&any_ts_type, model, &mut FxHashSet::default())
+        || is_type_promise_like(&any_ts_type, model, &mut FxHashSet::default())
+}
+
+/// Resolves `ty` — through any chain of type alias declarations — to a function
+/// type and classifies its return type as promise-like.
+///
+/// This matches both the direct `() => Promise<R>` annotation and the aliased
+/// `type Fetch = () => Promise<R>` form, where the annotation is a bare
+/// reference that `is_type_promise_like` alone would resolve to a function type
+/// and reject. `visited` guards against cyclic aliases.
+fn is_callable_returning_promise(
+    ty: &AnyTsType,
+    model: &SemanticModel,
+    visited: &mut FxHashSet<String>,
+) -> bool {
+    match ty {
+        AnyTsType::TsFunctionType(func_type) => func_type
+            .return_type()
+            .ok()
+            .and_then(|return_type| match return_type {
+                AnyTsReturnType::AnyTsType(ty) => Some(ty),
+                _ => None,
+            })
+            .is_some_and(|ty| is_type_promise_like(&ty, model, visited)),
+        AnyTsType::TsReferenceType(reference_type) => {
+            let Ok(AnyTsName::JsReferenceIdentifier(identifier)) = reference_type.name() else {
+                return false;
+            };
+            let Ok(name_token) = identifier.value_token() else {
+                return false;
+            };
+            if !visited.insert(name_token.text_trimmed().to_string()) {
+                return false;
             }
-            _ => None,
+            let Some(binding) = model.binding(&identifier) else {
+                return false;
+            };
+            let Some(AnyJsBindingDeclaration::TsTypeAliasDeclaration(alias)) =
+                binding.tree().declaration()
+            else {
+                return false;
+            };
+            alias
+                .ty()
+                .is_ok_and(|aliased| is_callable_returning_promise(&aliased, model, visited))
+        }
+        AnyTsType::TsParenthesizedType(parenthesized_type) => parenthesized_type
+            .ty()
+            .is_ok_and(|inner| is_callable_returning_promise(&inner, model, visited)),
+        _ => false,
+    }
+}
+
+/// A `promise.then(...)` chain decomposed into the pieces needed to rewrite it
+/// as `await`/`try`/`catch`.
+struct ThenChain {
+    /// The receiver the chain is called on, e.g. `doWork()` in `doWork().then()`.
+    promise: AnyJsExpression,
+    /// The fulfilled callback (an inline function literal).
+    fulfilled: AnyJsExpression,
+    /// The rejection callback, from `.then(_, onRejected)` or a trailing
+    /// `.catch(onError)`, if one is present.
+    rejected: Option<AnyJsExpression>,
+}
+
+/// Returns the static member callee of a call together with the member name.
+fn call_member(call: &JsCallExpression) -> Option<(JsStaticMemberExpression, String)> {
+    let AnyJsExpression::JsStaticMemberExpression(member) = call.callee().ok()? else {
+        return None;
+    };
+    let AnyJsName::JsName(name) = member.member().ok()? else {
+        return None;
+    };
+    Some((member, name.to_string()))
+}
+
+/// Decomposes `expression` into a [`ThenChain`], if it is one.
+///
+/// Two shapes are understood: `promise.then(onFulfilled, onRejected?)` and
+/// `promise.then(onFulfilled).catch(onError)`. A `.then` that already carries a
+/// rejection handler *and* a trailing `.catch` is too ambiguous to lower and is
+/// rejected.
+fn decompose_then_chain(expression: &AnyJsExpression) -> Option<ThenChain> {
+    let call = expression.as_js_call_expression()?;
+    let (member, name) = call_member(call)?;
+    match name.as_str() {
+        "catch" => {
+            let rejected = rejection_handler_argument(call, 0)?;
+            let inner = member.object().ok()?;
+            let inner_call = inner.as_js_call_expression()?;
+            let (then_member, then_name) = call_member(inner_call)?;
+            if then_name != "then" {
+                return None;
+            }
+            let then_args = inner_call
+                .arguments()
+                .ok()?
+                .args()
+                .iter()
+                .filter_map(|arg| arg.ok())
+                .count();
+            if then_args > 1 {
+                return None;
+            }
+            let fulfilled = rejection_handler_argument(inner_call, 0)?;
+            let promise = then_member.object().ok()?;
+            Some(ThenChain {
+                promise,
+                fulfilled,
+                rejected: Some(rejected),
+            })
+        }
+        "then" => {
+            let fulfilled = rejection_handler_argument(call, 0)?;
+            let rejected = rejection_handler_argument(call, 1);
+            let promise = member.object().ok()?;
+            Some(ThenChain {
+                promise,
+                fulfilled,
+                rejected,
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Builds the "Convert to async / await" code action for a `.then()` chain.
+///
+/// The chain is rewritten into an `await` statement whose result is bound to the
+/// fulfilled callback's parameter, the callback body is inlined, and a rejection
+/// handler is lowered into a surrounding `try`/`catch`. The transform bails out
+/// (returning `None`) whenever the callbacks are not inline function literals,
+/// reference `this`/`arguments`, or contain top-level `return` statements that
+/// cannot be safely lifted.
+fn convert_then_chain_to_async(
+    ctx: &RuleContext<NoFloatingPromises>,
+    node: &JsExpressionStatement,
+    expression: &AnyJsExpression,
+) -> Option<JsRuleAction> {
+    let chain = decompose_then_chain(expression)?;
+
+    let (fulfilled_param, fulfilled_body) = extract_callback(&chain.fulfilled)?;
+    let rejected = match &chain.rejected {
+        Some(rejected) => Some(extract_callback(rejected)?),
+        None => None,
+    };
+
+    let space = [(TriviaPieceKind::Whitespace, " ")];
+    let await_expression = AnyJsExpression::JsAwaitExpression(make::js_await_expression(
+        make::token(JsSyntaxKind::AWAIT_KW).with_trailing_trivia(space),
+        chain.promise.clone().trim_leading_trivia()?,
+    ));
+
+    let mut body_statements = Vec::new();
+    match fulfilled_param {
+        Some(name) => body_statements.push(make_const_await(&name, await_expression)),
+        None => body_statements.push(AnyJsStatement::JsExpressionStatement(
+            make::js_expression_statement(await_expression).build(),
+        )),
+    }
+    body_statements.extend(fulfilled_body);
+
+    let new_statement = match rejected {
+        Some((rejected_param, rejected_body)) => {
+            make_try_catch(body_statements, rejected_param, rejected_body)
+        }
+        None => AnyJsStatement::JsBlockStatement(make::js_block_statement(
+            make::token(JsSyntaxKind::L_CURLY),
+            make::js_statement_list(body_statements),
+            make::token(JsSyntaxKind::R_CURLY),
+        )),
+    };
+
+    let mut mutation = ctx.root().begin();
+    mutation.replace_node(
+        AnyJsStatement::JsExpressionStatement(node.clone()),
+        new_statement,
+    );
+    Some(JsRuleAction::new(
+        ctx.metadata().action_category(ctx.category(), ctx.group()),
+        ctx.metadata().applicability(),
+        markup! { "Convert to async / await." }.to_owned(),
+        mutation,
+    ))
+}
+
+/// Extracts the first parameter name and the body statements of an inline
+/// callback, or returns `None` when the callback cannot be safely inlined.
+fn extract_callback(expression: &AnyJsExpression) -> Option<(Option<String>, Vec<AnyJsStatement>)> {
+    match expression.clone().omit_parentheses() {
+        AnyJsExpression::JsArrowFunctionExpression(arrow) => {
+            let body = arrow.body().ok()?;
+            let statements = match body {
+                AnyJsFunctionBody::JsFunctionBody(block) => {
+                    let syntax = block.syntax();
+                    if references_this_or_arguments(syntax) || has_top_level_return(syntax) {
+                        return None;
+                    }
+                    block.statements().into_iter().collect()
+                }
+                AnyJsFunctionBody::AnyJsExpression(body_expression) => {
+                    if references_this_or_arguments(body_expression.syntax()) {
+                        return None;
+                    }
+                    vec![AnyJsStatement::JsExpressionStatement(
+                        make::js_expression_statement(body_expression.trim_leading_trivia()?)
+                            .build(),
+                    )]
+                }
+            };
+            Some((arrow_first_parameter_name(&arrow)?, statements))
+        }
+        AnyJsExpression::JsFunctionExpression(func) => {
+            let block = func.body().ok()?;
+            let syntax = block.syntax();
+            if references_this_or_arguments(syntax) || has_top_level_return(syntax) {
+                return None;
+            }
+            let param = first_parameter_name(&func.parameters().ok()?)?;
+            Some((param, block.statements().into_iter().collect()))
+        }
+        _ => None,
+    }
+}
+
+/// Returns `true` if the subtree references `this` or `arguments`.
+fn references_this_or_arguments(syntax: &JsSyntaxNode) -> bool {
+    syntax.descendants().any(|node| match node.kind() {
+        JsSyntaxKind::JS_THIS_EXPRESSION => true,
+        JsSyntaxKind::JS_IDENTIFIER_EXPRESSION => node.text_trimmed() == "arguments",
+        _ => false,
+    })
+}
+
+/// Returns `true` if the subtree contains a `return` statement that belongs to
+/// the callback itself rather than to a nested function.
+fn has_top_level_return(body: &JsSyntaxNode) -> bool {
+    body.descendants()
+        .filter(|node| node.kind() == JsSyntaxKind::JS_RETURN_STATEMENT)
+        .any(|ret| {
+            !ret.ancestors()
+                .skip(1)
+                .take_while(|ancestor| ancestor != body)
+                .any(|ancestor| is_function_like(ancestor.kind()))
         })
-        .unwrap_or(false)
+}
+
+fn is_function_like(kind: JsSyntaxKind) -> bool {
+    matches!(
+        kind,
+        JsSyntaxKind::JS_FUNCTION_EXPRESSION
+            | JsSyntaxKind::JS_FUNCTION_DECLARATION
+            | JsSyntaxKind::JS_ARROW_FUNCTION_EXPRESSION
+            | JsSyntaxKind::JS_METHOD_CLASS_MEMBER
+            | JsSyntaxKind::JS_METHOD_OBJECT_MEMBER
+    )
+}
+
+/// Resolves the first parameter of a callback to inline.
+///
+/// The outer `Option` distinguishes "safe to inline" from "bail out": `None`
+/// means the parameter exists but is not a plain identifier binding (object or
+/// array destructuring, a default value, a rest element, ...) and so cannot be
+/// bound to the awaited result without dropping it — the caller must fall back
+/// to the plain `await` fix. `Some(None)` means the callback takes no
+/// parameter; `Some(Some(name))` carries the identifier to bind.
+fn arrow_first_parameter_name(arrow: &JsArrowFunctionExpression) -> Option<Option<String>> {
+    match arrow.parameters().ok()? {
+        AnyJsArrowFunctionParameters::AnyJsBinding(binding) => binding_name(&binding).map(Some),
+        AnyJsArrowFunctionParameters::JsParameters(params) => first_parameter_name(&params),
+    }
+}
+
+/// See [`arrow_first_parameter_name`] for the meaning of the nested `Option`.
+fn first_parameter_name(params: &JsParameters) -> Option<Option<String>> {
+    let Some(first) = params.items().into_iter().filter_map(|item| item.ok()).next() else {
+        return Some(None);
+    };
+    match first {
+        AnyJsParameter::AnyJsFormalParameter(AnyJsFormalParameter::JsFormalParameter(formal)) => {
+            // A default value is lost when the parameter is lifted to a `const`
+            // binding, so such callbacks are not safe to inline.
+            if formal.initializer().is_some() {
+                return None;
+            }
+            match formal.binding().ok()? {
+                AnyJsBindingPattern::AnyJsBinding(binding) => binding_name(&binding).map(Some),
+                // Destructuring patterns have no single identifier to bind to.
+                _ => None,
+            }
+        }
+        // Rest parameters and other non-plain bindings can't be lifted.
+        _ => None,
+    }
+}
+
+fn binding_name(binding: &AnyJsBinding) -> Option<String> {
+    match binding {
+        AnyJsBinding::JsIdentifierBinding(identifier) => {
+            Some(identifier.name_token().ok()?.text_trimmed().to_string())
+        }
+        _ => None,
+    }
+}
+
+/// Builds a `const <name> = <init>;` statement.
+fn make_const_await(name: &str, init: AnyJsExpression) -> AnyJsStatement {
+    let space = [(TriviaPieceKind::Whitespace, " ")];
+    let binding = AnyJsBindingPattern::AnyJsBinding(AnyJsBinding::JsIdentifierBinding(
+        make::js_identifier_binding(make::ident(name)),
+    ));
+    let declarator = make::js_variable_declarator(binding)
+        .with_initializer(make::js_initializer_clause(
+            make::token(JsSyntaxKind::EQ)
+                .with_leading_trivia(space)
+                .with_trailing_trivia(space),
+            init,
+        ))
+        .build();
+    let declaration = make::js_variable_declaration(
+        make::token(JsSyntaxKind::CONST_KW).with_trailing_trivia(space),
+        make::js_variable_declarator_list([declarator], []),
+    );
+    AnyJsStatement::JsVariableStatement(
+        make::js_variable_statement(declaration)
+            .with_semicolon_token(make::token(JsSyntaxKind::SEMICOLON))
+            .build(),
+    )
+}
+
+/// Wraps `body` in a `try { body } catch (param?) { rejection }` statement.
+fn make_try_catch(
+    body: Vec<AnyJsStatement>,
+    rejection_param: Option<String>,
+    rejection_body: Vec<AnyJsStatement>,
+) -> AnyJsStatement {
+    let space = [(TriviaPieceKind::Whitespace, " ")];
+    let try_block = make::js_block_statement(
+        make::token(JsSyntaxKind::L_CURLY),
+        make::js_statement_list(body),
+        make::token(JsSyntaxKind::R_CURLY),
+    );
+    let catch_block = make::js_block_statement(
+        make::token(JsSyntaxKind::L_CURLY),
+        make::js_statement_list(rejection_body),
+        make::token(JsSyntaxKind::R_CURLY),
+    );
+    let catch_clause = make::js_catch_clause(
+        make::token(JsSyntaxKind::CATCH_KW).with_leading_trivia(space),
+        catch_block,
+    );
+    let catch_clause = match rejection_param {
+        Some(name) => {
+            let declaration = make::js_catch_declaration(
+                make::token(JsSyntaxKind::L_PAREN),
+                AnyJsBindingPattern::AnyJsBinding(AnyJsBinding::JsIdentifierBinding(
+                    make::js_identifier_binding(make::ident(&name)),
+                )),
+                make::token(JsSyntaxKind::R_PAREN),
+            )
+            .build();
+            catch_clause.with_declaration(declaration).build()
+        }
+        None => catch_clause.build(),
+    };
+    AnyJsStatement::JsTryStatement(make::js_try_statement(
+        make::token(JsSyntaxKind::TRY_KW).with_trailing_trivia(space),
+        try_block,
+        catch_clause,
+    ))
 }